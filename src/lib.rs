@@ -3,6 +3,12 @@
 mod constants;
 mod u32ct;
 mod util;
+mod util_shortint;
+
+// named `u32_shortint` rather than `u32` so paths like `u32::from_be_bytes`
+// elsewhere in this crate keep resolving to the primitive type, not this module
+#[path = "u32.rs"]
+mod u32_shortint;
 
 use std::array;
 
@@ -50,68 +56,131 @@ pub fn trivial_encrypt_input(message: Vec<u8>, server_key: &ServerKey) -> InputC
     encrypt_input_helper(message, |x| U32Ct::trivial_encrypt(x, server_key))
 }
 
+fn encrypt_hmac_input_helper(message: Vec<u8>, enc: impl Fn(u32) -> U32Ct) -> InputCiphertext {
+    assert_eq!(
+        message.len() % 4,
+        0,
+        "HMAC key/message must be word-aligned (a multiple of 4 bytes); the caller must pad it \
+         before encrypting, since zero-padding it here would silently change the authenticated bytes"
+    );
+    let inner = message.into_iter().array_chunks::<4>().map(u32::from_be_bytes).map(enc).collect();
+    InputCiphertext { inner }
+}
+
+/// Encrypt a key or message for use with [`hmac_sha256_tfhe`]. Unlike
+/// [`encrypt_input`], this does not apply the full SHA256 padding scheme
+/// (`hmac_sha256_tfhe` pads the `ipad`/`opad` concatenations internally), so
+/// `message` must already be a multiple of 4 bytes; this function cannot pad
+/// it for you without silently changing the bytes HMAC authenticates
+pub fn encrypt_hmac_input(message: Vec<u8>, client_key: &ClientKey) -> InputCiphertext {
+    encrypt_hmac_input_helper(message, |x| U32Ct::encrypt(x, client_key))
+}
+
+/// Trivially encrypt a key or message for use with [`hmac_sha256_tfhe`]. See
+/// [`encrypt_hmac_input`]
+pub fn trivial_encrypt_hmac_input(message: Vec<u8>, server_key: &ServerKey) -> InputCiphertext {
+    encrypt_hmac_input_helper(message, |x| U32Ct::trivial_encrypt(x, server_key))
+}
+
 fn round(alphabet: &mut [U32Ct; 8], kp: &U32Ct, server_key: &ServerKey) {
     let [a, b, c, d, e, f, g, h] = alphabet.get_many_mut([0, 1, 2, 3, 4, 5, 6, 7]).unwrap();
-    let t1 = h
-        .add(&capsigma1(e, server_key), server_key)
-        .add(&ch(e, f, g, server_key), server_key)
-        .add(kp, server_key);
-    let t2 = capsigma0(a, server_key).add(&maj(a, b, c, server_key), server_key);
+    let capsigma1_e = capsigma1(e, server_key);
+    let ch_efg = ch(e, f, g, server_key);
+    let t1 = U32Ct::add_many(&[h, &capsigma1_e, &ch_efg, kp], server_key);
+    let capsigma0_a = capsigma0(a, server_key);
+    let maj_abc = maj(a, b, c, server_key);
+    let t2 = U32Ct::add_many(&[&capsigma0_a, &maj_abc], server_key);
     *d = d.add(&t1, server_key);
     *h = t1.add(&t2, server_key);
 }
 
-/// Perform SHA256 of an [`InputCiphertext`] fully homomorphically
-pub fn sha256_tfhe(input_ct: &InputCiphertext, server_key: &ServerKey) -> DigestCiphertext {
-    let input_ct = &input_ct.inner;
-    let message_length = input_ct.len();
-    let blocks = message_length / 16;
-    assert_eq!(message_length % 16, 0);
-
-    let mut input_ct = input_ct.iter();
-    let mut s = H.map(|hi| U32Ct::trivial_encrypt(hi, server_key));
+/// Absorb one 16-word (512-bit) message block into `state`, advancing it to
+/// the next chaining value in place
+pub fn compress_block(state: &mut [U32Ct; 8], block: &[U32Ct; 16], server_key: &ServerKey) {
     let k = K.map(|ki| U32Ct::trivial_encrypt(ki, server_key));
 
-    for _ in 0..blocks {
-        let mut alphabet = s.clone();
+    let mut alphabet = state.clone();
+    let mut w = array::from_fn::<_, 16, _>(|i| {
+        let wi = block[i].clone();
 
-        let mut w = array::from_fn::<_, 16, _>(|i| {
-            let wi = input_ct.next().unwrap().clone();
+        let k_w = k[i].add(&wi, server_key);
+        round(&mut alphabet, &k_w, server_key);
 
-            let k_w = k[i].add(&wi, server_key);
-            round(&mut alphabet, &k_w, server_key);
+        alphabet.rotate_right(1);
 
-            alphabet.rotate_right(1);
+        wi
+    });
 
-            wi
-        });
+    #[allow(clippy::needless_range_loop)]
+    for i in 16..64 {
+        let [wo0, wo1, wo9, wo14] =
+            w.get_many_mut([i % 16, (i + 1) % 16, (i + 9) % 16, (i + 14) % 16]).unwrap();
 
-        #[allow(clippy::needless_range_loop)]
-        for i in 16..64 {
-            let [wo0, wo1, wo9, wo14] =
-                w.get_many_mut([i % 16, (i + 1) % 16, (i + 9) % 16, (i + 14) % 16]).unwrap();
+        #[cfg(not(feature = "rayon"))]
+        let (s0, s1) = (sigma0(wo1, server_key), sigma1(wo14, server_key));
+        #[cfg(feature = "rayon")]
+        let (s0, s1) =
+            rayon::join(|| sigma0(wo1, server_key), || sigma1(wo14, server_key));
 
-            let s0 = sigma0(wo1, server_key);
-            let s1 = sigma1(wo14, server_key);
+        *wo0 = U32Ct::add_many(&[wo0, &s0, &s1, wo9], server_key);
 
-            *wo0 = wo0.add(&s0, server_key).add(&s1, server_key).add(wo9, server_key);
+        let k_wo0 = wo0.add(&k[i], server_key);
+        round(&mut alphabet, &k_wo0, server_key);
 
-            let k_wo0 = wo0.add(&k[i], server_key);
-            round(&mut alphabet, &k_wo0, server_key);
+        alphabet.rotate_right(1);
+    }
 
-            alphabet.rotate_right(1);
-        }
+    for (s_i, alphabet_i) in state.iter_mut().zip(alphabet) {
+        *s_i = s_i.add(&alphabet_i, server_key);
+    }
+}
 
-        for (s_i, alphabet_i) in s.iter_mut().zip(alphabet) {
-            *s_i = s_i.add(&alphabet_i, server_key);
-        }
+/// The running chaining value of a SHA256 hash as it absorbs message blocks,
+/// letting callers hash data that arrives incrementally instead of holding
+/// the whole padded input in memory. It always starts from the variant's
+/// initial chaining value; resuming from another chaining value goes through
+/// [`compress_block`] on that state directly
+pub struct Sha256State {
+    state: [U32Ct; 8],
+}
+
+impl Sha256State {
+    /// Start a fresh hash from `variant`'s initial chaining value
+    pub fn new(variant: Variant, server_key: &ServerKey) -> Self {
+        Self { state: variant.iv().map(|hi| U32Ct::trivial_encrypt(hi, server_key)) }
+    }
+
+    /// Absorb one 16-word (512-bit) message block into the running state
+    pub fn update(&mut self, block_ct: &[U32Ct; 16], server_key: &ServerKey) {
+        compress_block(&mut self.state, block_ct, server_key);
+    }
+
+    /// Consume the state, yielding the digest over every block absorbed so far
+    pub fn finalize(self) -> DigestCiphertext {
+        DigestCiphertext { inner: self.state }
     }
+}
+
+/// Perform SHA256 or SHA224 (depending on `variant`) of an [`InputCiphertext`]
+/// fully homomorphically
+pub fn sha256_tfhe(
+    variant: Variant,
+    input_ct: &InputCiphertext,
+    server_key: &ServerKey,
+) -> DigestCiphertext {
+    let input_ct = &input_ct.inner;
+    assert_eq!(input_ct.len() % 16, 0);
 
-    DigestCiphertext { inner: s }
+    let mut state = Sha256State::new(variant, server_key);
+    for block in input_ct.chunks(16) {
+        let block: &[U32Ct; 16] = block.try_into().expect("chunk of 16 words");
+        state.update(block, server_key);
+    }
+    state.finalize()
 }
 
-/// Decrypt a [`DigestCiphertext`] with the same `ClientKey` that encrypted its
-/// [`InputCiphertext`]
+/// Decrypt a SHA256 [`DigestCiphertext`] with the same `ClientKey` that
+/// encrypted its [`InputCiphertext`]
 pub fn decrypt_hash(digest_ct: &DigestCiphertext, client_key: &ClientKey) -> [u8; 32] {
     digest_ct
         .inner
@@ -123,9 +192,238 @@ pub fn decrypt_hash(digest_ct: &DigestCiphertext, client_key: &ClientKey) -> [u8
         .expect("to flatten into [u8; 32]")
 }
 
+/// Decrypt a SHA224 [`DigestCiphertext`] (one produced by hashing with
+/// [`Variant::Sha224`]), dropping the final, unused state word
+pub fn decrypt_hash_224(digest_ct: &DigestCiphertext, client_key: &ClientKey) -> [u8; 28] {
+    digest_ct.inner[..7]
+        .iter()
+        .map(|ct| ct.decrypt(client_key))
+        .flat_map(u32::to_be_bytes)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("to flatten into [u8; 28]")
+}
+
+/// Turn a [`DigestCiphertext`] back into a padded [`InputCiphertext`] so it
+/// can be fed into another hash (e.g. the outer hash of an HMAC) without ever
+/// decrypting it. `prior_words` is the number of ciphertext words absorbed
+/// ahead of the digest (e.g. the `opad`-xored key block), needed to compute
+/// the correct SHA256 length suffix; see [`pad_words_after`]
+pub fn digest_to_input(
+    digest_ct: &DigestCiphertext,
+    prior_words: usize,
+    server_key: &ServerKey,
+) -> InputCiphertext {
+    InputCiphertext { inner: pad_words_after(&digest_ct.inner, prior_words, server_key) }
+}
+
+const HMAC_BLOCK_WORDS: usize = 16; // 64-byte SHA256 block size
+const IPAD: u32 = 0x36363636;
+const OPAD: u32 = 0x5c5c5c5c;
+
+/// Derive the block-sized key used by HMAC: hash `key_ct` down to a digest
+/// first if it's longer than one block, then zero-pad it out to a block
+fn derive_key_block(key_ct: &InputCiphertext, server_key: &ServerKey) -> Vec<U32Ct> {
+    let mut key_words = if key_ct.inner.len() > HMAC_BLOCK_WORDS {
+        let padded = InputCiphertext { inner: pad_words(&key_ct.inner, server_key) };
+        sha256_tfhe(Variant::Sha256, &padded, server_key).inner.to_vec()
+    } else {
+        key_ct.inner.clone()
+    };
+    let zero = U32Ct::trivial_encrypt(0, server_key);
+    key_words.resize_with(HMAC_BLOCK_WORDS, || zero.clone());
+    key_words
+}
+
+/// Compute `HMAC(key_ct, message_ct) = H((K' xor opad) || H((K' xor ipad) || message_ct))`
+/// fully homomorphically, where `K'` is `key_ct` hashed down (if needed) and
+/// zero-padded to the SHA256 block size. `key_ct` and `message_ct` should be
+/// produced with [`encrypt_hmac_input`]/[`trivial_encrypt_hmac_input`], not
+/// [`encrypt_input`], since the SHA256 padding of the `ipad`/`opad`
+/// concatenations is applied here
+pub fn hmac_sha256_tfhe(
+    key_ct: &InputCiphertext,
+    message_ct: &InputCiphertext,
+    server_key: &ServerKey,
+) -> DigestCiphertext {
+    let key_block = derive_key_block(key_ct, server_key);
+
+    let ipad = U32Ct::trivial_encrypt(IPAD, server_key);
+    let opad = U32Ct::trivial_encrypt(OPAD, server_key);
+    let inner_key: Vec<U32Ct> = key_block.iter().map(|w| w.bitxor(&ipad, server_key)).collect();
+    let outer_key: Vec<U32Ct> = key_block.iter().map(|w| w.bitxor(&opad, server_key)).collect();
+
+    let inner_rest = pad_words_after(&message_ct.inner, HMAC_BLOCK_WORDS, server_key);
+    let inner_input =
+        InputCiphertext { inner: inner_key.into_iter().chain(inner_rest).collect() };
+    let inner_digest = sha256_tfhe(Variant::Sha256, &inner_input, server_key);
+
+    let outer_rest = digest_to_input(&inner_digest, HMAC_BLOCK_WORDS, server_key).inner;
+    let outer_input =
+        InputCiphertext { inner: outer_key.into_iter().chain(outer_rest).collect() };
+    sha256_tfhe(Variant::Sha256, &outer_input, server_key)
+}
+
+/// An input to the SHA256/SHA224 hash function encrypted with the
+/// [`tfhe::shortint`]-backed [`u32_shortint::U32Ct`] instead of the per-bit
+/// [`u32ct::U32Ct`]. See [`sha256_tfhe_shortint`]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InputCiphertextShortint {
+    inner: Vec<u32_shortint::U32Ct>,
+}
+
+/// A SHA256/SHA224 digest encrypted with the shortint-backed
+/// [`u32_shortint::U32Ct`]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DigestCiphertextShortint {
+    inner: [u32_shortint::U32Ct; 8],
+}
+
+fn encrypt_input_shortint_helper(
+    message: Vec<u8>,
+    enc: impl Fn(u32) -> u32_shortint::U32Ct,
+) -> InputCiphertextShortint {
+    let message = pad_message(message);
+    let inner =
+        message.into_iter().array_chunks::<4>().map(u32::from_be_bytes).map(enc).collect();
+    InputCiphertextShortint { inner }
+}
+
+/// Pad and encrypt an input message for [`sha256_tfhe_shortint`]
+pub fn encrypt_input_shortint(
+    message: Vec<u8>,
+    client_key: &tfhe::shortint::ClientKey,
+) -> InputCiphertextShortint {
+    encrypt_input_shortint_helper(message, |x| u32_shortint::U32Ct::encrypt(x, client_key))
+}
+
+/// Pad and trivially encrypt an input message for [`sha256_tfhe_shortint`].
+/// This does not obfuscate the input; see [`trivial_encrypt_input`]
+pub fn trivial_encrypt_input_shortint(
+    message: Vec<u8>,
+    server_key: &tfhe::shortint::ServerKey,
+) -> InputCiphertextShortint {
+    encrypt_input_shortint_helper(message, |x| u32_shortint::U32Ct::trivial_encrypt(x, server_key))
+}
+
+fn round_shortint(
+    alphabet: &mut [u32_shortint::U32Ct; 8],
+    kp: &u32_shortint::U32Ct,
+    server_key: &tfhe::shortint::ServerKey,
+) {
+    let [a, b, c, d, e, f, g, h] = alphabet.get_many_mut([0, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+    let t1 = h
+        .add(&util_shortint::capsigma1(e, server_key), server_key)
+        .add(&util_shortint::ch(e, f, g, server_key), server_key)
+        .add(kp, server_key);
+    let t2 = util_shortint::capsigma0(a, server_key)
+        .add(&util_shortint::maj(a, b, c, server_key), server_key);
+    *d = d.add(&t1, server_key);
+    *h = t1.add(&t2, server_key);
+}
+
+/// Absorb one 16-word message block into `state` using the shortint backend,
+/// the nibble-wise analogue of [`compress_block`]
+fn compress_block_shortint(
+    state: &mut [u32_shortint::U32Ct; 8],
+    block: &[u32_shortint::U32Ct; 16],
+    server_key: &tfhe::shortint::ServerKey,
+) {
+    let k = K.map(|ki| u32_shortint::U32Ct::trivial_encrypt(ki, server_key));
+
+    let mut alphabet = state.clone();
+    let mut w = array::from_fn::<_, 16, _>(|i| {
+        let wi = block[i].clone();
+
+        let k_w = k[i].add(&wi, server_key);
+        round_shortint(&mut alphabet, &k_w, server_key);
+
+        alphabet.rotate_right(1);
+
+        wi
+    });
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 16..64 {
+        let [wo0, wo1, wo9, wo14] =
+            w.get_many_mut([i % 16, (i + 1) % 16, (i + 9) % 16, (i + 14) % 16]).unwrap();
+
+        let s0 = util_shortint::sigma0(wo1, server_key);
+        let s1 = util_shortint::sigma1(wo14, server_key);
+        *wo0 = wo0.add(&s0, server_key).add(&s1, server_key).add(wo9, server_key);
+
+        let k_wo0 = wo0.add(&k[i], server_key);
+        round_shortint(&mut alphabet, &k_wo0, server_key);
+
+        alphabet.rotate_right(1);
+    }
+
+    for (s_i, alphabet_i) in state.iter_mut().zip(alphabet) {
+        *s_i = s_i.add(&alphabet_i, server_key);
+    }
+}
+
+/// Perform SHA256 or SHA224 fully homomorphically using the
+/// [`tfhe::shortint`]-backed nibble representation of [`u32_shortint::U32Ct`]
+/// instead of the per-bit boolean gates of [`sha256_tfhe`]: every bitwise op
+/// runs as one programmable bootstrap per nibble over 4-bit lookup tables
+/// rather than one boolean gate per bit, and rotates and shifts that are not
+/// a multiple of 4 bits blend adjacent nibbles the same way (the boolean path
+/// rotates and shifts by reordering bits, with no gates). The bivariate lookups pack both
+/// nibbles into one pre-bootstrap value (`left * message_modulus + right`),
+/// so parameters need `carry_modulus >= message_modulus` for that packed
+/// value to stay in range; use e.g. `PARAM_MESSAGE_4_CARRY_4`, not a
+/// `CARRY_1` set
+pub fn sha256_tfhe_shortint(
+    variant: Variant,
+    input_ct: &InputCiphertextShortint,
+    server_key: &tfhe::shortint::ServerKey,
+) -> DigestCiphertextShortint {
+    let input_ct = &input_ct.inner;
+    assert_eq!(input_ct.len() % 16, 0);
+
+    let mut state = variant.iv().map(|hi| u32_shortint::U32Ct::trivial_encrypt(hi, server_key));
+    for block in input_ct.chunks(16) {
+        let block: &[u32_shortint::U32Ct; 16] = block.try_into().expect("chunk of 16 words");
+        compress_block_shortint(&mut state, block, server_key);
+    }
+    DigestCiphertextShortint { inner: state }
+}
+
+/// Decrypt a SHA256 [`DigestCiphertextShortint`]
+pub fn decrypt_hash_shortint(
+    digest_ct: &DigestCiphertextShortint,
+    client_key: &tfhe::shortint::ClientKey,
+) -> [u8; 32] {
+    digest_ct
+        .inner
+        .iter()
+        .map(|ct| ct.decrypt(client_key))
+        .flat_map(u32::to_be_bytes)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("to flatten into [u8; 32]")
+}
+
+/// Decrypt a SHA224 [`DigestCiphertextShortint`] (one produced by hashing
+/// with [`Variant::Sha224`]), dropping the final, unused state word. See
+/// [`decrypt_hash_224`]
+pub fn decrypt_hash_224_shortint(
+    digest_ct: &DigestCiphertextShortint,
+    client_key: &tfhe::shortint::ClientKey,
+) -> [u8; 28] {
+    digest_ct.inner[..7]
+        .iter()
+        .map(|ct| ct.decrypt(client_key))
+        .flat_map(u32::to_be_bytes)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("to flatten into [u8; 28]")
+}
+
 #[cfg(test)]
 mod tests {
-    use sha2::{Digest, Sha256};
+    use sha2::{Digest, Sha224, Sha256};
     use tfhe::boolean::gen_keys;
 
     use super::*;
@@ -135,7 +433,7 @@ mod tests {
         let (client_key, server_key) = gen_keys();
         let input = b"".to_vec();
         let input_ct = trivial_encrypt_input(input.clone(), &server_key);
-        let hash_ct = sha256_tfhe(&input_ct, &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha256, &input_ct, &server_key);
         let hash = decrypt_hash(&hash_ct, &client_key);
         let expected_hash = Sha256::digest(input);
         assert_eq!(&hash, expected_hash.as_slice());
@@ -146,7 +444,7 @@ mod tests {
         let (client_key, server_key) = gen_keys();
         let input = b"hello world".to_vec();
         let input_ct = trivial_encrypt_input(input.clone(), &server_key);
-        let hash_ct = sha256_tfhe(&input_ct, &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha256, &input_ct, &server_key);
         let hash = decrypt_hash(&hash_ct, &client_key);
         let expected_hash = Sha256::digest(input);
         assert_eq!(&hash, expected_hash.as_slice());
@@ -157,19 +455,112 @@ mod tests {
         let (client_key, server_key) = gen_keys();
         let input = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu".to_vec();
         let input_ct = trivial_encrypt_input(input.clone(), &server_key);
-        let hash_ct = sha256_tfhe(&input_ct, &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha256, &input_ct, &server_key);
         let hash = decrypt_hash(&hash_ct, &client_key);
         let expected_hash = Sha256::digest(input);
         assert_eq!(&hash, expected_hash.as_slice());
     }
 
+    #[test]
+    fn test_sha224_empty_input_trivial() {
+        let (client_key, server_key) = gen_keys();
+        let input = b"".to_vec();
+        let input_ct = trivial_encrypt_input(input.clone(), &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha224, &input_ct, &server_key);
+        let hash = decrypt_hash_224(&hash_ct, &client_key);
+        let expected_hash = Sha224::digest(input);
+        assert_eq!(&hash, expected_hash.as_slice());
+    }
+
+    #[test]
+    fn test_sha224_small_input_trivial() {
+        let (client_key, server_key) = gen_keys();
+        let input = b"hello world".to_vec();
+        let input_ct = trivial_encrypt_input(input.clone(), &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha224, &input_ct, &server_key);
+        let hash = decrypt_hash_224(&hash_ct, &client_key);
+        let expected_hash = Sha224::digest(input);
+        assert_eq!(&hash, expected_hash.as_slice());
+    }
+
+    #[test]
+    fn test_sha224_larger_input_trivial() {
+        let (client_key, server_key) = gen_keys();
+        let input = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu".to_vec();
+        let input_ct = trivial_encrypt_input(input.clone(), &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha224, &input_ct, &server_key);
+        let hash = decrypt_hash_224(&hash_ct, &client_key);
+        let expected_hash = Sha224::digest(input);
+        assert_eq!(&hash, expected_hash.as_slice());
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let (client_key, server_key) = gen_keys();
+        let input = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu".to_vec();
+        let input_ct = trivial_encrypt_input(input.clone(), &server_key);
+
+        let mut state = Sha256State::new(Variant::Sha256, &server_key);
+        for block in input_ct.inner.chunks(16) {
+            let block: &[U32Ct; 16] = block.try_into().unwrap();
+            state.update(block, &server_key);
+        }
+        let hash = decrypt_hash(&state.finalize(), &client_key);
+
+        let expected_hash = Sha256::digest(input);
+        assert_eq!(&hash, expected_hash.as_slice());
+    }
+
+    fn reference_hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+        let mut key_block = [0u8; 64];
+        if key.len() > 64 {
+            key_block[..32].copy_from_slice(&Sha256::digest(key));
+        } else {
+            key_block[..key.len()].copy_from_slice(key);
+        }
+        let ipad: Vec<u8> = key_block.iter().map(|b| b ^ 0x36).collect();
+        let opad: Vec<u8> = key_block.iter().map(|b| b ^ 0x5c).collect();
+        let inner = Sha256::digest([ipad, message.to_vec()].concat());
+        Sha256::digest([opad, inner.to_vec()].concat()).into()
+    }
+
+    #[test]
+    fn test_hmac_sha256_trivial() {
+        let (client_key, server_key) = gen_keys();
+        let key = b"Jefe".to_vec();
+        let message = b"what do ya want for nothing?".to_vec();
+        let expected_hash = reference_hmac_sha256(&key, &message);
+
+        let key_ct = trivial_encrypt_hmac_input(key, &server_key);
+        let message_ct = trivial_encrypt_hmac_input(message, &server_key);
+        let hash_ct = hmac_sha256_tfhe(&key_ct, &message_ct, &server_key);
+        let hash = decrypt_hash(&hash_ct, &client_key);
+        assert_eq!(hash, expected_hash);
+    }
+
+    #[test]
+    fn test_hmac_sha256_long_key_trivial() {
+        let (client_key, server_key) = gen_keys();
+        let key: Vec<u8> = (0..80).collect();
+        // a word-aligned variant of the RFC 4231 long-key test vector's
+        // message, since encrypt_hmac_input requires a multiple of 4 bytes
+        let message = b"Test Using Larger Than Block-Size Key - Hash Key First!!".to_vec();
+        let expected_hash = reference_hmac_sha256(&key, &message);
+
+        let key_ct = trivial_encrypt_hmac_input(key, &server_key);
+        let message_ct = trivial_encrypt_hmac_input(message, &server_key);
+        let hash_ct = hmac_sha256_tfhe(&key_ct, &message_ct, &server_key);
+        let hash = decrypt_hash(&hash_ct, &client_key);
+        assert_eq!(hash, expected_hash);
+    }
+
     #[test]
     #[ignore]
     fn test_empty_input() {
         let (client_key, server_key) = gen_keys();
         let input = b"".to_vec();
         let input_ct = encrypt_input(input.clone(), &client_key);
-        let hash_ct = sha256_tfhe(&input_ct, &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha256, &input_ct, &server_key);
         let hash = decrypt_hash(&hash_ct, &client_key);
         let expected_hash = Sha256::digest(input);
         assert_eq!(&hash, expected_hash.as_slice());
@@ -181,7 +572,7 @@ mod tests {
         let (client_key, server_key) = gen_keys();
         let input = b"hello world".to_vec();
         let input_ct = encrypt_input(input.clone(), &client_key);
-        let hash_ct = sha256_tfhe(&input_ct, &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha256, &input_ct, &server_key);
         let hash = decrypt_hash(&hash_ct, &client_key);
         let expected_hash = Sha256::digest(input);
         assert_eq!(&hash, expected_hash.as_slice());
@@ -193,9 +584,49 @@ mod tests {
         let (client_key, server_key) = gen_keys();
         let input = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu".to_vec();
         let input_ct = encrypt_input(input.clone(), &client_key);
-        let hash_ct = sha256_tfhe(&input_ct, &server_key);
+        let hash_ct = sha256_tfhe(Variant::Sha256, &input_ct, &server_key);
         let hash = decrypt_hash(&hash_ct, &client_key);
         let expected_hash = Sha256::digest(input);
         assert_eq!(&hash, expected_hash.as_slice());
     }
+
+    fn shortint_keys() -> (tfhe::shortint::ClientKey, tfhe::shortint::ServerKey) {
+        let client_key =
+            tfhe::shortint::ClientKey::new(tfhe::shortint::parameters::PARAM_MESSAGE_4_CARRY_4);
+        let server_key = tfhe::shortint::ServerKey::new(&client_key);
+        (client_key, server_key)
+    }
+
+    #[test]
+    fn test_empty_input_trivial_shortint() {
+        let (client_key, server_key) = shortint_keys();
+        let input = b"".to_vec();
+        let input_ct = trivial_encrypt_input_shortint(input.clone(), &server_key);
+        let hash_ct = sha256_tfhe_shortint(Variant::Sha256, &input_ct, &server_key);
+        let hash = decrypt_hash_shortint(&hash_ct, &client_key);
+        let expected_hash = Sha256::digest(input);
+        assert_eq!(&hash, expected_hash.as_slice());
+    }
+
+    #[test]
+    fn test_small_input_trivial_shortint() {
+        let (client_key, server_key) = shortint_keys();
+        let input = b"hello world".to_vec();
+        let input_ct = trivial_encrypt_input_shortint(input.clone(), &server_key);
+        let hash_ct = sha256_tfhe_shortint(Variant::Sha256, &input_ct, &server_key);
+        let hash = decrypt_hash_shortint(&hash_ct, &client_key);
+        let expected_hash = Sha256::digest(input);
+        assert_eq!(&hash, expected_hash.as_slice());
+    }
+
+    #[test]
+    fn test_sha224_small_input_trivial_shortint() {
+        let (client_key, server_key) = shortint_keys();
+        let input = b"hello world".to_vec();
+        let input_ct = trivial_encrypt_input_shortint(input.clone(), &server_key);
+        let hash_ct = sha256_tfhe_shortint(Variant::Sha224, &input_ct, &server_key);
+        let hash = decrypt_hash_224_shortint(&hash_ct, &client_key);
+        let expected_hash = Sha224::digest(input);
+        assert_eq!(&hash, expected_hash.as_slice());
+    }
 }
@@ -1,7 +1,22 @@
 use std::mem::{self, MaybeUninit};
 
-use tfhe::shortint::{Ciphertext, ClientKey};
+use serde::{Deserialize, Serialize};
+use tfhe::shortint::{Ciphertext, ClientKey, ServerKey};
 
+/// A 32-bit ciphertext backed by [`tfhe::shortint`] rather than
+/// [`crate::u32ct::U32Ct`]'s per-bit booleans: each of the eight nibbles is
+/// its own shortint ciphertext. The bitwise ops below run one programmable
+/// bootstrap per nibble (a lookup table over one or two nibbles), 8 per word
+/// instead of the 32 boolean gates of the per-bit type; `add` takes five
+/// bivariate bootstraps per nibble. Rotates and shifts by a multiple of 4
+/// only reorder nibbles, while other amounts take one bootstrap per nibble
+/// (the per-bit type rotates and shifts without any gate). The
+/// bivariate ops (`bitxor`, `bitand`, the rotate/shift blend, and the adder)
+/// pack both nibbles into one pre-bootstrap value via
+/// `unchecked_apply_lookup_table_bivariate`, which only stays in range when
+/// `carry_modulus >= message_modulus`; parameters must be chosen accordingly
+/// (e.g. `PARAM_MESSAGE_4_CARRY_4`, not a `CARRY_1` set)
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct U32Ct {
     inner: [Ciphertext; 8], // least significant short first
 }
@@ -20,6 +35,15 @@ impl U32Ct {
         Self { inner }
     }
 
+    pub fn trivial_encrypt(mut x: u32, server_key: &ServerKey) -> Self {
+        let inner = array_from_fn_8(|_| {
+            let nibble = (x & 0b1111) as u64;
+            x >>= 4;
+            server_key.create_trivial(nibble)
+        });
+        Self { inner }
+    }
+
     pub fn decrypt(&self, client_key: &ClientKey) -> u32 {
         let mut plaintext = 0;
         for short in self.inner.iter().rev() {
@@ -30,9 +54,134 @@ impl U32Ct {
         plaintext
     }
 
-    // add/add scalar
-    // rotate left/right by scalar
-    // bitxor, bitand, bitneg,
+    /// Nibble-wise XOR via a bivariate lookup table: one bootstrap per nibble
+    /// pair instead of four boolean XOR gates
+    pub fn bitxor(&self, other: &Self, server_key: &ServerKey) -> Self {
+        let acc = server_key.generate_accumulator_bivariate(|x, y| x ^ y);
+        let inner = self
+            .inner
+            .each_ref()
+            .zip(other.inner.each_ref())
+            .map(|(l, r)| server_key.unchecked_apply_lookup_table_bivariate(l, r, &acc));
+        Self { inner }
+    }
+
+    /// Nibble-wise AND via a bivariate lookup table
+    pub fn bitand(&self, other: &Self, server_key: &ServerKey) -> Self {
+        let acc = server_key.generate_accumulator_bivariate(|x, y| x & y);
+        let inner = self
+            .inner
+            .each_ref()
+            .zip(other.inner.each_ref())
+            .map(|(l, r)| server_key.unchecked_apply_lookup_table_bivariate(l, r, &acc));
+        Self { inner }
+    }
+
+    /// Nibble-wise complement (mod 16) via a univariate lookup table
+    pub fn bitnot(&self, server_key: &ServerKey) -> Self {
+        let acc = server_key.generate_accumulator(|x| !x & 0b1111);
+        let inner = self.inner.each_ref().map(|n| server_key.apply_lookup_table(n, &acc));
+        Self { inner }
+    }
+
+    /// Add `self` and `other` mod 2^32, propagating carry nibble-to-nibble:
+    /// the shortint analogue of [`crate::u32ct::U32Ct::add`]'s bit-to-bit
+    /// ripple carry, four bits wide per step instead of one
+    pub fn add(&self, other: &Self, server_key: &ServerKey) -> Self {
+        let mut carry = server_key.create_trivial(0);
+        let inner = self.inner.each_ref().zip(other.inner.each_ref()).map(|(a, b)| {
+            let s;
+            (s, carry) = full_adder_nibble(a, b, &carry, server_key);
+            s
+        });
+        Self { inner }
+    }
+
+    /// Rotate the 32-bit integer right by `shift` bits. Whole nibbles move by
+    /// reordering ciphertexts; the remaining sub-nibble rotation blends each
+    /// pair of adjacent nibbles through a bivariate lookup table
+    pub fn rotate_right(&self, shift: usize, server_key: &ServerKey) -> Self {
+        let shift = shift % 32;
+        let nibble_shift = shift / 4;
+        let bit_shift = shift % 4;
+
+        // rotating the integer right requires moving ciphertexts left
+        let mut inner = self.inner.clone();
+        inner.rotate_left(nibble_shift);
+        if bit_shift == 0 {
+            return Self { inner };
+        }
+
+        let acc = server_key.generate_accumulator_bivariate(move |lo, hi| {
+            (lo >> bit_shift | hi << (4 - bit_shift)) & 0b1111
+        });
+        let blended = array_from_fn_8(|i| {
+            let hi = &inner[(i + 1) % 8];
+            server_key.unchecked_apply_lookup_table_bivariate(&inner[i], hi, &acc)
+        });
+        Self { inner: blended }
+    }
+
+    /// Logical shift of the 32-bit integer right by `shift` bits, filling
+    /// with zero nibbles/bits from the top. See [`Self::rotate_right`] for
+    /// how the sub-nibble remainder is blended
+    pub fn shift_right(&self, shift: usize, server_key: &ServerKey) -> Self {
+        let shift = shift.min(32);
+        let nibble_shift = shift / 4;
+        let bit_shift = shift % 4;
+
+        let zero = server_key.create_trivial(0);
+        let mut inner = self.inner.clone();
+        inner.rotate_left(nibble_shift);
+        for i in (8 - nibble_shift)..8 {
+            inner[i] = zero.clone();
+        }
+        if bit_shift == 0 {
+            return Self { inner };
+        }
+
+        let acc = server_key.generate_accumulator_bivariate(move |lo, hi| {
+            (lo >> bit_shift | hi << (4 - bit_shift)) & 0b1111
+        });
+        let blended = array_from_fn_8(|i| {
+            let hi = if i == 7 { &zero } else { &inner[i + 1] };
+            server_key.unchecked_apply_lookup_table_bivariate(&inner[i], hi, &acc)
+        });
+        Self { inner: blended }
+    }
+}
+
+fn array_from_fn_8(mut f: impl FnMut(usize) -> Ciphertext) -> [Ciphertext; 8] {
+    let mut inner: [MaybeUninit<Ciphertext>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+    for (i, elem) in inner.iter_mut().enumerate() {
+        elem.write(f(i));
+    }
+    unsafe { mem::transmute::<_, [Ciphertext; 8]>(inner) }
+}
+
+/// A nibble-wide full adder: sums `a`, `b`, and carry-in `c_in` (each in
+/// `0..16`, with `c_in` always 0 or 1) mod 16, plus the carry out of that
+/// sum. Mirrors [`crate::u32ct::full_adder`] a nibble at a time instead of a
+/// bit at a time
+fn full_adder_nibble(
+    a: &Ciphertext,
+    b: &Ciphertext,
+    c_in: &Ciphertext,
+    server_key: &ServerKey,
+) -> (Ciphertext, Ciphertext) {
+    let sum_acc = server_key.generate_accumulator_bivariate(|x, y| (x + y) % 16);
+    let carry_acc = server_key.generate_accumulator_bivariate(|x, y| u64::from(x + y >= 16));
+    let sum_ab = server_key.unchecked_apply_lookup_table_bivariate(a, b, &sum_acc);
+    let carry_ab = server_key.unchecked_apply_lookup_table_bivariate(a, b, &carry_acc);
+
+    let sum = server_key.unchecked_apply_lookup_table_bivariate(&sum_ab, c_in, &sum_acc);
+    let carry_abc = server_key.unchecked_apply_lookup_table_bivariate(&sum_ab, c_in, &carry_acc);
+
+    let carry_out_acc = server_key.generate_accumulator_bivariate(|x, y| u64::from(x + y >= 1));
+    let carry_out =
+        server_key.unchecked_apply_lookup_table_bivariate(&carry_ab, &carry_abc, &carry_out_acc);
+
+    (sum, carry_out)
 }
 
 #[cfg(test)]
@@ -41,14 +190,82 @@ mod tests {
 
     use super::*;
 
+    fn keys() -> (ClientKey, ServerKey) {
+        let client_key = ClientKey::new(parameters::PARAM_MESSAGE_4_CARRY_4);
+        let server_key = ServerKey::new(&client_key);
+        (client_key, server_key)
+    }
+
     #[test]
     fn test_encrypt_decrypt() {
-        let key = ClientKey::new(parameters::PARAM_MESSAGE_4_CARRY_1);
-        let ct = U32Ct::encrypt(0, &key);
-        let pt = ct.decrypt(&key);
+        let (client_key, _) = keys();
+        let ct = U32Ct::encrypt(0, &client_key);
+        let pt = ct.decrypt(&client_key);
         assert_eq!(pt, 0);
-        let ct = U32Ct::encrypt(234353, &key);
-        let pt = ct.decrypt(&key);
+        let ct = U32Ct::encrypt(234353, &client_key);
+        let pt = ct.decrypt(&client_key);
         assert_eq!(pt, 234353);
     }
+
+    #[test]
+    fn test_bitxor() {
+        let (client_key, server_key) = keys();
+        let ct1 = U32Ct::encrypt(3472387250, &client_key);
+        let ct2 = U32Ct::encrypt(964349245, &client_key);
+        let r = ct1.bitxor(&ct2, &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 3472387250 ^ 964349245);
+    }
+
+    #[test]
+    fn test_bitand() {
+        let (client_key, server_key) = keys();
+        let ct1 = U32Ct::encrypt(3472387250, &client_key);
+        let ct2 = U32Ct::encrypt(964349245, &client_key);
+        let r = ct1.bitand(&ct2, &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 3472387250 & 964349245);
+    }
+
+    #[test]
+    fn test_bitnot() {
+        let (client_key, server_key) = keys();
+        let ct = U32Ct::encrypt(3472387250, &client_key);
+        let r = ct.bitnot(&server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, !3472387250u32);
+    }
+
+    #[test]
+    fn test_add() {
+        let (client_key, server_key) = keys();
+        let ct1 = U32Ct::encrypt(33, &client_key);
+        let ct2 = U32Ct::encrypt(36, &client_key);
+        let r = ct1.add(&ct2, &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 33u32.wrapping_add(36));
+        let ct1 = U32Ct::encrypt(4294967295, &client_key);
+        let ct2 = U32Ct::encrypt(2, &client_key);
+        let r = ct1.add(&ct2, &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 1);
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let (client_key, server_key) = keys();
+        let ct = U32Ct::encrypt(3472387250, &client_key);
+        let r = ct.rotate_right(13, &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 3472387250u32.rotate_right(13));
+    }
+
+    #[test]
+    fn test_shift_right() {
+        let (client_key, server_key) = keys();
+        let ct = U32Ct::encrypt(3472387250, &client_key);
+        let r = ct.shift_right(13, &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 3472387250u32 >> 13);
+    }
 }
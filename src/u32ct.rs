@@ -25,6 +25,13 @@ impl U32Ct {
         from_bits(self.inner.each_ref().map(|b| client_key.decrypt(b)))
     }
 
+    /// Add `self` and `other` mod 2^32 via ripple-carry: each bit's carry-out
+    /// feeds the next bit's full adder, so unlike [`crate::util::ch`]/`maj` or
+    /// the bitwise ops below, this has a genuine 32-deep sequential
+    /// dependency and must not be parallelized across bits (a rayon `map`
+    /// over `full_adder` calls would read each `carry` before the previous
+    /// bit writes it, silently producing a wrong sum). See [`Self::add_many`]
+    /// for summing multiple addends without paying for that chain repeatedly
     pub fn add(&self, other: &Self, server_key: &ServerKey) -> Self {
         let mut carry = server_key.trivial_encrypt(false);
         let inner = self.inner.each_ref().zip(other.inner.each_ref()).map(|(a, b)| {
@@ -35,24 +42,98 @@ impl U32Ct {
         Self { inner }
     }
 
+    /// Sum any number of addends mod 2^32. Rather than chaining ripple-carry
+    /// `add`s (one 32-deep sequential gate dependency per addend), reduce the
+    /// addends three at a time through carry-save compressors, which have no
+    /// cross-bit dependency, until two vectors remain, then ripple-carry those
+    /// together once. This turns N-1 sequential ripple-carry adds into a
+    /// shallow tree of compressor layers followed by a single ripple-carry add
+    pub fn add_many(addends: &[&Self], server_key: &ServerKey) -> Self {
+        assert!(!addends.is_empty(), "add_many requires at least one addend");
+        let mut operands: Vec<Self> = addends.iter().map(|ct| (*ct).clone()).collect();
+        while operands.len() > 2 {
+            let mut next = Vec::with_capacity(operands.len());
+            for chunk in operands.chunks(3) {
+                match chunk {
+                    [a, b, c] => {
+                        let (sum, carry) = carry_save_compress(a, b, c, server_key);
+                        next.push(sum);
+                        next.push(carry);
+                    }
+                    rest => next.extend(rest.iter().cloned()),
+                }
+            }
+            operands = next;
+        }
+        match operands.as_slice() {
+            [a, b] => a.add(b, server_key),
+            [a] => a.clone(),
+            _ => unreachable!("operands reduced to 0 or more than 2 elements"),
+        }
+    }
+
+    #[cfg(not(feature = "rayon"))]
     pub fn bitxor(&self, other: &Self, server_key: &ServerKey) -> Self {
         let inner =
             self.inner.each_ref().zip(other.inner.each_ref()).map(|(l, r)| server_key.xor(l, r));
         Self { inner }
     }
 
+    #[cfg(feature = "rayon")]
+    pub fn bitxor(&self, other: &Self, server_key: &ServerKey) -> Self {
+        use rayon::prelude::*;
+        let inner: Vec<BoolCt> = self
+            .inner
+            .par_iter()
+            .zip(other.inner.par_iter())
+            .map(|(l, r)| server_key.xor(l, r))
+            .collect();
+        Self { inner: inner.try_into().unwrap() }
+    }
+
+    #[cfg(not(feature = "rayon"))]
     pub fn bitand(&self, other: &Self, server_key: &ServerKey) -> Self {
         let inner =
             self.inner.each_ref().zip(other.inner.each_ref()).map(|(l, r)| server_key.and(l, r));
         Self { inner }
     }
 
+    #[cfg(feature = "rayon")]
+    pub fn bitand(&self, other: &Self, server_key: &ServerKey) -> Self {
+        use rayon::prelude::*;
+        let inner: Vec<BoolCt> = self
+            .inner
+            .par_iter()
+            .zip(other.inner.par_iter())
+            .map(|(l, r)| server_key.and(l, r))
+            .collect();
+        Self { inner: inner.try_into().unwrap() }
+    }
+
+    pub fn bitnot(&self, server_key: &ServerKey) -> Self {
+        let inner = self.inner.each_ref().map(|b| server_key.not(b));
+        Self { inner }
+    }
+
+    #[cfg(not(feature = "rayon"))]
     pub fn bitor(&self, other: &Self, server_key: &ServerKey) -> Self {
         let inner =
             self.inner.each_ref().zip(other.inner.each_ref()).map(|(l, r)| server_key.or(l, r));
         Self { inner }
     }
 
+    #[cfg(feature = "rayon")]
+    pub fn bitor(&self, other: &Self, server_key: &ServerKey) -> Self {
+        use rayon::prelude::*;
+        let inner: Vec<BoolCt> = self
+            .inner
+            .par_iter()
+            .zip(other.inner.par_iter())
+            .map(|(l, r)| server_key.or(l, r))
+            .collect();
+        Self { inner: inner.try_into().unwrap() }
+    }
+
     pub fn rotate_right(&self, shift: usize) -> Self {
         // rotating the integer right requires moving ciphertexts left
         let mut inner = self.inner.clone();
@@ -88,6 +169,27 @@ fn full_adder(a: &BoolCt, b: &BoolCt, c_in: &BoolCt, server_key: &ServerKey) ->
     (s, c_out)
 }
 
+/// A 3:2 carry-save compressor: reduces three addends to a sum vector and a
+/// carry vector, with every bit position computed independently (no carry
+/// propagation between positions). The carry out of bit `i` is written to bit
+/// `i + 1` of the carry vector; the carry out of bit 31 is discarded, matching
+/// the mod 2^32 semantics of [`U32Ct::add`]
+fn carry_save_compress(a: &U32Ct, b: &U32Ct, c: &U32Ct, server_key: &ServerKey) -> (U32Ct, U32Ct) {
+    let mut sum_bits = Vec::with_capacity(32);
+    let mut carry_bits = Vec::with_capacity(32);
+    carry_bits.push(server_key.trivial_encrypt(false));
+    for i in 0..32 {
+        let (s, c_out) = full_adder(&a.inner[i], &b.inner[i], &c.inner[i], server_key);
+        sum_bits.push(s);
+        if i < 31 {
+            carry_bits.push(c_out);
+        }
+    }
+    let sum: [BoolCt; 32] = sum_bits.try_into().unwrap();
+    let carry: [BoolCt; 32] = carry_bits.try_into().unwrap();
+    (U32Ct { inner: sum }, U32Ct { inner: carry })
+}
+
 #[cfg(test)]
 mod tests {
     use tfhe::boolean::gen_keys;
@@ -197,4 +299,31 @@ mod tests {
         let pt = r.decrypt(&client_key);
         assert_eq!(pt, 1);
     }
+
+    #[test]
+    fn test_add_many() {
+        let (client_key, server_key) = gen_keys();
+        let ct1 = U32Ct::encrypt(33, &client_key);
+        let ct2 = U32Ct::encrypt(36, &client_key);
+        let r = U32Ct::add_many(&[&ct1, &ct2], &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 33u32.wrapping_add(36));
+
+        let ct1 = U32Ct::encrypt(4294967295, &client_key);
+        let ct2 = U32Ct::encrypt(2, &client_key);
+        let ct3 = U32Ct::encrypt(17, &client_key);
+        let ct4 = U32Ct::encrypt(3000000000, &client_key);
+        let r = U32Ct::add_many(&[&ct1, &ct2, &ct3, &ct4], &server_key);
+        let pt = r.decrypt(&client_key);
+        let expected = 4294967295u32
+            .wrapping_add(2)
+            .wrapping_add(17)
+            .wrapping_add(3000000000);
+        assert_eq!(pt, expected);
+
+        let ct1 = U32Ct::encrypt(42, &client_key);
+        let r = U32Ct::add_many(&[&ct1], &server_key);
+        let pt = r.decrypt(&client_key);
+        assert_eq!(pt, 42);
+    }
 }
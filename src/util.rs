@@ -14,6 +14,30 @@ pub fn pad_message(mut msg: Vec<u8>) -> Vec<u8> {
     msg
 }
 
+/// Apply the SHA256 padding scheme to `words`, a sequence of already-encrypted
+/// 32-bit words, producing a ciphertext vector whose length is a multiple of
+/// 16. The padding bytes (the `0x80` delimiter, the zero fill, and the 64-bit
+/// bit-length suffix) depend only on the public word count, not on the
+/// encrypted content, so they are trivially encrypted rather than requiring a
+/// client-side pad-then-encrypt pass like [`pad_message`]
+pub fn pad_words(words: &[U32Ct], server_key: &ServerKey) -> Vec<U32Ct> {
+    pad_words_after(words, 0, server_key)
+}
+
+/// Like [`pad_words`], but accounts for `prior_words` ciphertext words that
+/// were already absorbed ahead of `words` (e.g. via
+/// [`crate::Sha256State::update`]) when computing the length suffix, so
+/// `words` need not hold the whole message to be padded correctly
+pub fn pad_words_after(words: &[U32Ct], prior_words: usize, server_key: &ServerKey) -> Vec<U32Ct> {
+    let total_bytes = (words.len() + prior_words) * 4;
+    let padded_template = pad_message(vec![0u8; total_bytes]);
+    let suffix = padded_template[total_bytes..]
+        .chunks(4)
+        .map(|c| u32::from_be_bytes(c.try_into().unwrap()))
+        .map(|w| U32Ct::trivial_encrypt(w, server_key));
+    words.iter().cloned().chain(suffix).collect()
+}
+
 pub fn sigma0(x: &U32Ct, server_key: &ServerKey) -> U32Ct {
     let rotate_7 = x.rotate_right(7);
     let rotate_18 = x.rotate_right(18);
@@ -46,6 +70,11 @@ pub fn capsigma1(x: &U32Ct, server_key: &ServerKey) -> U32Ct {
     xor_6_11.bitxor(&rotate_25, server_key)
 }
 
+/// `ch`'s two `bitand`s are independent of each other (neither reads a bit
+/// the other writes), so splitting them across threads under the `rayon`
+/// feature is safe. Contrast [`U32Ct::add`], whose ripple carry has a real
+/// bit-to-bit dependency and is deliberately never parallelized
+#[cfg(not(feature = "rayon"))]
 pub fn ch(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
     let left = x.bitand(y, server_key);
     let not_x = x.bitnot(server_key);
@@ -53,6 +82,16 @@ pub fn ch(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
     left.bitxor(&right, server_key)
 }
 
+#[cfg(feature = "rayon")]
+pub fn ch(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let (left, right) = rayon::join(
+        || x.bitand(y, server_key),
+        || x.bitnot(server_key).bitand(z, server_key),
+    );
+    left.bitxor(&right, server_key)
+}
+
+#[cfg(not(feature = "rayon"))]
 pub fn maj(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
     let left = x.bitand(y, server_key);
     let middle = x.bitand(z, server_key);
@@ -61,6 +100,16 @@ pub fn maj(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
     fold_l.bitxor(&right, server_key)
 }
 
+#[cfg(feature = "rayon")]
+pub fn maj(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let (left, (middle, right)) = rayon::join(
+        || x.bitand(y, server_key),
+        || rayon::join(|| x.bitand(z, server_key), || y.bitand(z, server_key)),
+    );
+    let fold_l = left.bitxor(&middle, server_key);
+    fold_l.bitxor(&right, server_key)
+}
+
 #[cfg(test)]
 mod tests {
     use tfhe::boolean::gen_keys;
@@ -99,6 +148,28 @@ mod tests {
         assert_eq!(padded, expected);
     }
 
+    #[test]
+    fn test_pad_words() {
+        let (client_key, server_key) = gen_keys();
+        let raw = b"hello world!".to_vec(); // 12 bytes, 3 words
+        let words: Vec<U32Ct> = raw
+            .chunks(4)
+            .map(|c| u32::from_be_bytes(c.try_into().unwrap()))
+            .map(|w| U32Ct::encrypt(w, &client_key))
+            .collect();
+
+        let padded = pad_words(&words, &server_key);
+
+        let expected_bytes = pad_message(raw);
+        let expected: Vec<u32> =
+            expected_bytes.chunks(4).map(|c| u32::from_be_bytes(c.try_into().unwrap())).collect();
+
+        assert_eq!(padded.len(), expected.len());
+        for (word_ct, expected_word) in padded.iter().zip(expected) {
+            assert_eq!(word_ct.decrypt(&client_key), expected_word);
+        }
+    }
+
     type Scramble1 = fn(&U32Ct, &ServerKey) -> U32Ct;
     type Scramble3 = fn(&U32Ct, &U32Ct, &U32Ct, &ServerKey) -> U32Ct;
 
@@ -0,0 +1,114 @@
+use tfhe::shortint::ServerKey;
+
+use crate::u32_shortint::U32Ct;
+
+pub fn sigma0(x: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let rotate_7 = x.rotate_right(7, server_key);
+    let rotate_18 = x.rotate_right(18, server_key);
+    let shift_3 = x.shift_right(3, server_key);
+    let xor_7_18 = rotate_7.bitxor(&rotate_18, server_key);
+    xor_7_18.bitxor(&shift_3, server_key)
+}
+
+pub fn sigma1(x: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let rotate_17 = x.rotate_right(17, server_key);
+    let rotate_19 = x.rotate_right(19, server_key);
+    let shift_10 = x.shift_right(10, server_key);
+    let xor_17_19 = rotate_17.bitxor(&rotate_19, server_key);
+    xor_17_19.bitxor(&shift_10, server_key)
+}
+
+pub fn capsigma0(x: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let rotate_2 = x.rotate_right(2, server_key);
+    let rotate_13 = x.rotate_right(13, server_key);
+    let rotate_22 = x.rotate_right(22, server_key);
+    let xor_2_13 = rotate_2.bitxor(&rotate_13, server_key);
+    xor_2_13.bitxor(&rotate_22, server_key)
+}
+
+pub fn capsigma1(x: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let rotate_6 = x.rotate_right(6, server_key);
+    let rotate_11 = x.rotate_right(11, server_key);
+    let rotate_25 = x.rotate_right(25, server_key);
+    let xor_6_11 = rotate_6.bitxor(&rotate_11, server_key);
+    xor_6_11.bitxor(&rotate_25, server_key)
+}
+
+pub fn ch(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let left = x.bitand(y, server_key);
+    let not_x = x.bitnot(server_key);
+    let right = not_x.bitand(z, server_key);
+    left.bitxor(&right, server_key)
+}
+
+pub fn maj(x: &U32Ct, y: &U32Ct, z: &U32Ct, server_key: &ServerKey) -> U32Ct {
+    let left = x.bitand(y, server_key);
+    let middle = x.bitand(z, server_key);
+    let right = y.bitand(z, server_key);
+    let fold_l = left.bitxor(&middle, server_key);
+    fold_l.bitxor(&right, server_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use tfhe::shortint::{parameters, ClientKey};
+
+    use super::*;
+
+    fn keys() -> (ClientKey, ServerKey) {
+        let client_key = ClientKey::new(parameters::PARAM_MESSAGE_4_CARRY_4);
+        let server_key = ServerKey::new(&client_key);
+        (client_key, server_key)
+    }
+
+    type Scramble1 = fn(&U32Ct, &ServerKey) -> U32Ct;
+    type Scramble3 = fn(&U32Ct, &U32Ct, &U32Ct, &ServerKey) -> U32Ct;
+
+    fn test_scamble1(scramble: Scramble1, x: u32, y_expected: u32) {
+        let (client_key, server_key) = keys();
+        let x = U32Ct::encrypt(x, &client_key);
+        let y_ct = scramble(&x, &server_key);
+        let y = y_ct.decrypt(&client_key);
+        assert_eq!(y, y_expected);
+    }
+
+    fn test_scramble3(scramble: Scramble3, a: u32, b: u32, c: u32, y_expected: u32) {
+        let (client_key, server_key) = keys();
+        let a = U32Ct::encrypt(a, &client_key);
+        let b = U32Ct::encrypt(b, &client_key);
+        let c = U32Ct::encrypt(c, &client_key);
+        let y_ct = scramble(&a, &b, &c, &server_key);
+        let y = y_ct.decrypt(&client_key);
+        assert_eq!(y, y_expected);
+    }
+
+    #[test]
+    fn test_sigma0() {
+        test_scamble1(sigma0, 0b1111111111111111, 0b11000001111111111101111000000000);
+    }
+
+    #[test]
+    fn test_sigma1() {
+        test_scamble1(sigma1, 0b1111111111111111, 0b01100000000000000110000000111111);
+    }
+
+    #[test]
+    fn test_capsigma0() {
+        test_scamble1(capsigma0, 0b1111111111111111, 0b00111100000001111100001111111000);
+    }
+
+    #[test]
+    fn test_capsigma1() {
+        test_scamble1(capsigma1, 0b1111111111111111, 0b00000011100111111111110001100000);
+    }
+
+    #[test]
+    fn test_ch() {
+        test_scramble3(ch, 0xaaaa, 0xbbbb, 0xcccc, 61166);
+    }
+
+    #[test]
+    fn test_maj() {
+        test_scramble3(maj, 0xaaaa, 0xbbbb, 0xcccc, 43690);
+    }
+}